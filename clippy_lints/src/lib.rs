@@ -0,0 +1,34 @@
+#![feature(box_syntax)]
+#![feature(rustc_private)]
+
+#[macro_use]
+extern crate rustc;
+#[macro_use]
+extern crate rustc_session;
+
+mod utils;
+
+mod mem_replace;
+mod methods;
+mod tabs_in_doc_comments;
+
+pub use crate::utils::conf::Conf;
+
+pub fn register_plugins(store: &mut rustc::lint::LintStore, conf: &Conf) {
+    store.register_lints(&[
+        mem_replace::MEM_REPLACE_OPTION_WITH_NONE,
+        mem_replace::MEM_REPLACE_WITH_UNINIT,
+        mem_replace::MEM_REPLACE_WITH_DEFAULT,
+        mem_replace::MEM_REPLACE_SPLIT_OFF_ZERO,
+        methods::OPTION_MAP_UNWRAP_OR,
+        methods::OPTION_MAP_UNWRAP_OR_ELSE,
+        methods::STRLEN_ON_C_STRINGS,
+        tabs_in_doc_comments::TABS_IN_DOC_COMMENTS,
+    ]);
+
+    let doc_tab_width = conf.doc_tab_width;
+
+    store.register_late_pass(|| box mem_replace::MemReplace);
+    store.register_late_pass(|| box methods::Methods);
+    store.register_early_pass(move || box tabs_in_doc_comments::TabsInDocComments::new(doc_tab_width));
+}
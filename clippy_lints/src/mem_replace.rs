@@ -1,6 +1,6 @@
 use crate::utils::{
-    in_macro, match_def_path, match_qpath, paths, snippet, snippet_with_applicability, span_help_and_lint,
-    span_lint_and_sugg, span_lint_and_then,
+    in_macro, match_def_path, match_qpath, match_type, paths, snippet, snippet_with_applicability,
+    span_help_and_lint, span_lint_and_sugg, span_lint_and_then,
 };
 use if_chain::if_chain;
 use rustc::declare_lint_pass;
@@ -9,6 +9,7 @@ use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintP
 use rustc_errors::Applicability;
 use rustc_session::declare_tool_lint;
 use rustc_span::source_map::Span;
+use syntax::ast::LitKind;
 
 declare_clippy_lint! {
     /// **What it does:** Checks for `mem::replace()` on an `Option` with
@@ -93,8 +94,38 @@ declare_clippy_lint! {
     "replacing a value of type `T` with `T::default()` instead of using `std::mem::take`"
 }
 
-declare_lint_pass!(MemReplace =>
-    [MEM_REPLACE_OPTION_WITH_NONE, MEM_REPLACE_WITH_UNINIT, MEM_REPLACE_WITH_DEFAULT]);
+declare_clippy_lint! {
+    /// **What it does:** Checks for `Vec::split_off(0)` and `String::split_off(0)`, which are
+    /// used to take the entirety of the receiver while leaving it empty.
+    ///
+    /// **Why is this bad?** The standard library no longer special-cases `split_off(0)`, so it
+    /// now has to move every remaining element into a freshly allocated buffer. `std::mem::take`
+    /// achieves the same result by swapping in the type's default value, without touching the
+    /// contents.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut vec = vec![1, 2, 3];
+    /// let taken = vec.split_off(0);
+    /// ```
+    /// Is better expressed with:
+    /// ```rust
+    /// let mut vec = vec![1, 2, 3];
+    /// let taken = std::mem::take(&mut vec);
+    /// ```
+    pub MEM_REPLACE_SPLIT_OFF_ZERO,
+    style,
+    "replacing a `Vec` or `String` with its contents via `split_off(0)` instead of `std::mem::take`"
+}
+
+declare_lint_pass!(MemReplace => [
+    MEM_REPLACE_OPTION_WITH_NONE,
+    MEM_REPLACE_WITH_UNINIT,
+    MEM_REPLACE_WITH_DEFAULT,
+    MEM_REPLACE_SPLIT_OFF_ZERO,
+]);
 
 fn check_replace_option_with_none(cx: &LateContext<'_, '_>, src: &Expr<'_>, dest: &Expr<'_>, expr_span: Span) {
     if let ExprKind::Path(ref replacement_qpath) = src.kind {
@@ -195,6 +226,35 @@ fn check_replace_with_default(cx: &LateContext<'_, '_>, src: &Expr<'_>, dest: &E
     }
 }
 
+fn check_split_off_zero(cx: &LateContext<'_, '_>, expr: &Expr<'_>) {
+    if_chain! {
+        if !in_external_macro(cx.tcx.sess, expr.span);
+        if let ExprKind::MethodCall(ref path, _, ref args) = expr.kind;
+        if path.ident.as_str() == "split_off";
+        if let [recv, idx] = &**args;
+        if let ExprKind::Lit(ref lit) = idx.kind;
+        if let LitKind::Int(0, _) = lit.node;
+        then {
+            let recv_ty = cx.tables.expr_ty(recv);
+            if match_type(cx, recv_ty, &paths::VEC) || match_type(cx, recv_ty, &paths::STRING) {
+                if !in_macro(expr.span) {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let recv_snippet = snippet_with_applicability(cx, recv.span, "_", &mut applicability);
+                    span_lint_and_sugg(
+                        cx,
+                        MEM_REPLACE_SPLIT_OFF_ZERO,
+                        expr.span,
+                        "replacing with `split_off(0)` is less efficient than using `mem::take`",
+                        "consider using",
+                        format!("std::mem::take(&mut {})", recv_snippet),
+                        applicability,
+                    );
+                }
+            }
+        }
+    }
+}
+
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MemReplace {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! {
@@ -210,5 +270,6 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MemReplace {
                 check_replace_with_default(cx, src, dest, expr.span);
             }
         }
+        check_split_off_zero(cx, expr);
     }
 }
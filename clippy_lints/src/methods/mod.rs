@@ -0,0 +1,86 @@
+mod option_map_unwrap_or;
+mod strlen_on_c_strings;
+
+use crate::utils::method_chain_args;
+use rustc::declare_lint_pass;
+use rustc::hir::{Expr, ExprKind};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc_session::declare_tool_lint;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `option.map(f).unwrap_or(a)`.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `option.map_or(a, f)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = Some(1);
+    /// x.map(|a| a + 1).unwrap_or(0);
+    /// ```
+    pub OPTION_MAP_UNWRAP_OR,
+    style,
+    "using `Option.map(f).unwrap_or(a)`, which is more succinctly expressed as `map_or(a, f)`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `option.map(f).unwrap_or_else(g)`.
+    ///
+    /// **Why is this bad?** Readability, this can be written more concisely as
+    /// `option.map_or_else(g, f)`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # let x = Some(1);
+    /// x.map(|a| a + 1).unwrap_or_else(|| 0);
+    /// ```
+    pub OPTION_MAP_UNWRAP_OR_ELSE,
+    style,
+    "using `Option.map(f).unwrap_or_else(g)`, which is more succinctly expressed as `map_or_else(g, f)`"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls to `libc::strlen` on a pointer obtained from
+    /// `CStr::as_ptr` or `CString::as_ptr`.
+    ///
+    /// **Why is this bad?** The owning `CStr`/`CString` already knows its length, so re-deriving
+    /// it with an `unsafe` FFI call forces an unnecessary `O(n)` rescan of the buffer.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// # use std::ffi::CStr;
+    /// # let s = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+    /// let len = unsafe { libc::strlen(s.as_ptr()) };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::ffi::CStr;
+    /// # let s = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+    /// let len = s.to_bytes().len();
+    /// ```
+    pub STRLEN_ON_C_STRINGS,
+    complexity,
+    "using `libc::strlen` on a `CStr`/`CString` instead of `to_bytes().len()`"
+}
+
+declare_lint_pass!(Methods => [OPTION_MAP_UNWRAP_OR, OPTION_MAP_UNWRAP_OR_ELSE, STRLEN_ON_C_STRINGS]);
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Methods {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::MethodCall(..) = expr.kind {
+            if let Some(arglists) = method_chain_args(expr, &["map", "unwrap_or"]) {
+                option_map_unwrap_or::lint(cx, expr, arglists[0].1, arglists[1].1, arglists[0].0);
+            } else if let Some(arglists) = method_chain_args(expr, &["map", "unwrap_or_else"]) {
+                option_map_unwrap_or::lint_unwrap_or_else(cx, expr, arglists[0].1, arglists[1].1, arglists[0].0);
+            }
+        }
+
+        strlen_on_c_strings::check(cx, expr);
+    }
+}
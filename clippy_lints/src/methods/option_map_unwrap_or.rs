@@ -8,7 +8,7 @@ use rustc_errors::Applicability;
 use rustc_span::source_map::Span;
 use rustc_span::symbol::Symbol;
 
-use super::OPTION_MAP_UNWRAP_OR;
+use super::{OPTION_MAP_UNWRAP_OR, OPTION_MAP_UNWRAP_OR_ELSE};
 
 /// lint use of `map().unwrap_or()` for `Option`s
 pub(super) fn lint<'a, 'tcx>(
@@ -85,6 +85,60 @@ pub(super) fn lint<'a, 'tcx>(
     }
 }
 
+/// lint use of `map().unwrap_or_else()` for `Option`s
+pub(super) fn lint_unwrap_or_else<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &hir::Expr<'_>,
+    map_args: &'tcx [hir::Expr<'_>],
+    unwrap_args: &'tcx [hir::Expr<'_>],
+    map_span: Span,
+) {
+    // lint if the caller of `map()` is an `Option`
+    if match_type(cx, cx.tables.expr_ty(&map_args[0]), &paths::OPTION) {
+        // Do not lint if the `map` argument uses identifiers in the `map`
+        // argument that are also used in the `unwrap_or_else` argument
+
+        let mut unwrap_visitor = UnwrapVisitor {
+            cx,
+            identifiers: FxHashSet::default(),
+        };
+        unwrap_visitor.visit_expr(&unwrap_args[1]);
+
+        let mut map_expr_visitor = MapExprVisitor {
+            cx,
+            identifiers: unwrap_visitor.identifiers,
+            found_identifier: false,
+        };
+        map_expr_visitor.visit_expr(&map_args[1]);
+
+        if map_expr_visitor.found_identifier {
+            return;
+        }
+
+        if differing_macro_contexts(unwrap_args[1].span, map_span) {
+            return;
+        }
+
+        let mut applicability = Applicability::MachineApplicable;
+        // get snippet for unwrap_or_else()
+        let unwrap_snippet = snippet_with_applicability(cx, unwrap_args[1].span, "..", &mut applicability);
+        let msg = "called `map(f).unwrap_or_else(g)` on an `Option` value. This can be done more directly by \
+                   calling `map_or_else(g, f)` instead";
+
+        span_lint_and_then(cx, OPTION_MAP_UNWRAP_OR_ELSE, expr.span, msg, |db| {
+            let map_arg_span = map_args[1].span;
+
+            let suggestion = vec![
+                (map_span, String::from("map_or_else")),
+                (expr.span.with_lo(unwrap_args[0].span.hi()), String::from("")),
+                (map_arg_span.with_hi(map_arg_span.lo()), format!("{}, ", unwrap_snippet)),
+            ];
+
+            db.multipart_suggestion("use `map_or_else(g, f)` instead", suggestion, applicability);
+        });
+    }
+}
+
 struct UnwrapVisitor<'a, 'tcx> {
     cx: &'a LateContext<'a, 'tcx>,
     identifiers: FxHashSet<Symbol>,
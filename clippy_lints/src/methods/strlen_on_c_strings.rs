@@ -0,0 +1,41 @@
+use crate::utils::{match_def_path, match_type, snippet_with_applicability, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc::hir::{Expr, ExprKind};
+use rustc::lint::LateContext;
+use rustc_errors::Applicability;
+
+use super::STRLEN_ON_C_STRINGS;
+
+const STRLEN: [&str; 2] = ["libc", "strlen"];
+const CSTR: [&str; 4] = ["std", "ffi", "c_str", "CStr"];
+const CSTRING: [&str; 4] = ["std", "ffi", "c_str", "CString"];
+
+/// lint use of `libc::strlen` on the pointer obtained from `CStr`/`CString::as_ptr`
+pub(super) fn check(cx: &LateContext<'_, '_>, expr: &Expr<'_>) {
+    if_chain! {
+        if let ExprKind::Call(ref func, ref args) = expr.kind;
+        if let ExprKind::Path(ref func_qpath) = func.kind;
+        if let Some(def_id) = cx.tables.qpath_res(func_qpath, func.hir_id).opt_def_id();
+        if match_def_path(cx, def_id, &STRLEN);
+        if let [ptr_arg] = &**args;
+        if let ExprKind::MethodCall(ref method, _, ref method_args) = ptr_arg.kind;
+        if method.ident.as_str() == "as_ptr";
+        if let [recv] = &**method_args;
+        then {
+            let recv_ty = cx.tables.expr_ty(recv).peel_refs();
+            if match_type(cx, recv_ty, &CSTR) || match_type(cx, recv_ty, &CSTRING) {
+                let mut applicability = Applicability::MachineApplicable;
+                let recv_snippet = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
+                span_lint_and_sugg(
+                    cx,
+                    STRLEN_ON_C_STRINGS,
+                    expr.span,
+                    "using `libc::strlen` on a `CStr`/`CString` obtained from `as_ptr`",
+                    "try this instead",
+                    format!("{}.to_bytes().len()", recv_snippet),
+                    applicability,
+                );
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::utils::span_lint_and_sugg;
-use rustc::declare_lint_pass;
+use rustc::impl_lint_pass;
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc_errors::Applicability;
 use rustc_session::declare_tool_lint;
@@ -17,6 +17,9 @@ declare_clippy_lint! {
     ///
     /// **Known problems:** None.
     ///
+    /// **Configuration:** The `doc-tab-width` configuration option controls the tab width (in
+    /// columns) used when expanding a tab to the next tab stop. Default: `4`.
+    ///
     /// **Example:**
     /// ```rust
     /// ///
@@ -57,14 +60,22 @@ declare_clippy_lint! {
     "using tabs in doc comments is not recommended"
 }
 
-declare_lint_pass!(TabsInDocComments => [TABS_IN_DOC_COMMENTS]);
+/// Lints doc comments for tab characters, expanding each one to the next tab stop rather than a
+/// flat number of spaces so ascii-diagrams stay aligned.
+pub struct TabsInDocComments {
+    width: u32,
+}
 
 impl TabsInDocComments {
-    fn warn_if_tabs_in_doc(cx: &EarlyContext<'_>, attr: &ast::Attribute) {
+    pub fn new(width: u32) -> Self {
+        Self { width: width.max(1) }
+    }
+
+    fn warn_if_tabs_in_doc(&self, cx: &EarlyContext<'_>, attr: &ast::Attribute) {
         if let ast::AttrKind::DocComment(comment) = attr.kind {
             let comment = comment.as_str();
 
-            for (lo, hi) in get_chunks_of_tabs(&comment) {
+            for (lo, hi, replacement_width) in get_chunks_of_tabs(self.width, &comment) {
                 let new_span = Span::new(
                     attr.span.lo() + BytePos(lo),
                     attr.span.lo() + BytePos(hi),
@@ -75,8 +86,8 @@ impl TabsInDocComments {
                     TABS_IN_DOC_COMMENTS,
                     new_span,
                     "using tabs in doc comments is not recommended",
-                    "consider using four spaces per tab",
-                    "    ".repeat((hi - lo) as usize),
+                    "consider using spaces instead",
+                    " ".repeat(replacement_width as usize),
                     Applicability::MaybeIncorrect,
                 );
             }
@@ -84,61 +95,61 @@ impl TabsInDocComments {
     }
 }
 
+impl_lint_pass!(TabsInDocComments => [TABS_IN_DOC_COMMENTS]);
+
 impl EarlyLintPass for TabsInDocComments {
     fn check_attribute(&mut self, cx: &EarlyContext<'_>, attribute: &ast::Attribute) {
-        Self::warn_if_tabs_in_doc(cx, &attribute);
+        self.warn_if_tabs_in_doc(cx, &attribute);
     }
 }
 
 ///
-/// scans the string for groups of tabs and returns the start(inclusive) and end positions
-/// (exclusive) of all groups
-/// e.g. "sd\tasd\t\taa" will be converted to [(2, 3), (6, 8)] as
+/// Scans the string for groups of tabs and returns, for each group, the start (inclusive) and
+/// end (exclusive) *byte* offsets into `the_str`, together with the number of spaces the group
+/// should be replaced with so that it lands on the same column a `width`-wide terminal tab stop
+/// would. Column tracking (and thus the tab-stop alignment) resets at the start of each line,
+/// since doc comments are rendered one line at a time.
+/// e.g. "sd\tasd\t\taa" with `width` 4 is converted to [(2, 3, 2), (6, 8, 5)] as
 ///       012 3456 7 89
 ///         ^-^  ^---^
-fn get_chunks_of_tabs(the_str: &str) -> Vec<(u32, u32)> {
-    let line_length_way_to_long = "doc comment longer than 2^32 chars";
-    let mut spans: Vec<(u32, u32)> = vec![];
-    let mut current_start: u32 = 0;
-
-    // tracker to decide if the last group of tabs is not closed by a non-tab character
-    let mut is_active = false;
-
-    let chars_array: Vec<_> = the_str.chars().collect();
-
-    if chars_array == vec!['\t'] {
-        return vec![(0, 1)];
-    }
-
-    for (index, arr) in chars_array.windows(2).enumerate() {
-        let index = u32::try_from(index).expect(line_length_way_to_long);
-        match arr {
-            ['\t', '\t'] => {
-                // either string starts with double tab, then we have to set it active,
-                // otherwise is_active is true anyway
-                is_active = true;
+fn get_chunks_of_tabs(width: u32, the_str: &str) -> Vec<(u32, u32, u32)> {
+    let line_too_long = "doc comment longer than 2^32 bytes";
+    let mut spans: Vec<(u32, u32, u32)> = vec![];
+
+    let mut col: u32 = 0;
+    let mut byte_pos: u32 = 0;
+    // (start byte offset of the current run of tabs, spaces needed to replace it so far)
+    let mut current_group: Option<(u32, u32)> = None;
+
+    for ch in the_str.chars() {
+        let ch_len = u32::try_from(ch.len_utf8()).expect(line_too_long);
+
+        match ch {
+            '\n' => {
+                if let Some((start, replacement_width)) = current_group.take() {
+                    spans.push((start, byte_pos, replacement_width));
+                }
+                col = 0;
             },
-            [_, '\t'] => {
-                // as ['\t', '\t'] is excluded, this has to be a start of a tab group,
-                // set indices accordingly
-                is_active = true;
-                current_start = index + 1;
+            '\t' => {
+                let next_stop = (col / width + 1) * width;
+                let (start, replacement_width) = current_group.unwrap_or((byte_pos, 0));
+                current_group = Some((start, replacement_width + (next_stop - col)));
+                col = next_stop;
             },
-            ['\t', _] => {
-                // this now has to be an end of the group, hence we have to push a new tuple
-                is_active = false;
-                spans.push((current_start, index + 1));
+            _ => {
+                if let Some((start, replacement_width)) = current_group.take() {
+                    spans.push((start, byte_pos, replacement_width));
+                }
+                col += 1;
             },
-            _ => {},
         }
+
+        byte_pos += ch_len;
     }
 
-    // only possible when tabs are at the end, insert last group
-    if is_active {
-        spans.push((
-            current_start,
-            u32::try_from(the_str.chars().count()).expect(line_length_way_to_long),
-        ));
+    if let Some((start, replacement_width)) = current_group.take() {
+        spans.push((start, byte_pos, replacement_width));
     }
 
     spans
@@ -150,71 +161,102 @@ mod tests_for_get_chunks_of_tabs {
 
     #[test]
     fn test_empty_string() {
-        let res = get_chunks_of_tabs("");
+        let res = get_chunks_of_tabs(4, "");
 
         assert_eq!(res, vec![]);
     }
 
     #[test]
     fn test_simple() {
-        let res = get_chunks_of_tabs("sd\t\t\taa");
+        let res = get_chunks_of_tabs(4, "sd\t\t\taa");
 
-        assert_eq!(res, vec![(2, 5)]);
+        assert_eq!(res, vec![(2, 5, 10)]);
     }
 
     #[test]
     fn test_only_t() {
-        let res = get_chunks_of_tabs("\t\t");
+        let res = get_chunks_of_tabs(4, "\t\t");
 
-        assert_eq!(res, vec![(0, 2)]);
+        assert_eq!(res, vec![(0, 2, 8)]);
     }
 
     #[test]
     fn test_only_one_t() {
-        let res = get_chunks_of_tabs("\t");
+        let res = get_chunks_of_tabs(4, "\t");
 
-        assert_eq!(res, vec![(0, 1)]);
+        assert_eq!(res, vec![(0, 1, 4)]);
     }
 
     #[test]
     fn test_double() {
-        let res = get_chunks_of_tabs("sd\tasd\t\taa");
+        let res = get_chunks_of_tabs(4, "sd\tasd\t\taa");
 
-        assert_eq!(res, vec![(2, 3), (6, 8)]);
+        assert_eq!(res, vec![(2, 3, 2), (6, 8, 5)]);
     }
 
     #[test]
     fn test_start() {
-        let res = get_chunks_of_tabs("\t\taa");
+        let res = get_chunks_of_tabs(4, "\t\taa");
 
-        assert_eq!(res, vec![(0, 2)]);
+        assert_eq!(res, vec![(0, 2, 8)]);
     }
 
     #[test]
     fn test_end() {
-        let res = get_chunks_of_tabs("aa\t\t");
+        let res = get_chunks_of_tabs(4, "aa\t\t");
 
-        assert_eq!(res, vec![(2, 4)]);
+        assert_eq!(res, vec![(2, 4, 6)]);
     }
 
     #[test]
     fn test_start_single() {
-        let res = get_chunks_of_tabs("\taa");
+        let res = get_chunks_of_tabs(4, "\taa");
 
-        assert_eq!(res, vec![(0, 1)]);
+        assert_eq!(res, vec![(0, 1, 4)]);
     }
 
     #[test]
     fn test_end_single() {
-        let res = get_chunks_of_tabs("aa\t");
+        let res = get_chunks_of_tabs(4, "aa\t");
 
-        assert_eq!(res, vec![(2, 3)]);
+        assert_eq!(res, vec![(2, 3, 2)]);
     }
 
     #[test]
     fn test_no_tabs() {
-        let res = get_chunks_of_tabs("dsfs");
+        let res = get_chunks_of_tabs(4, "dsfs");
 
         assert_eq!(res, vec![]);
     }
+
+    #[test]
+    fn test_tab_stop_alignment() {
+        // a tab after two columns only needs two spaces to reach the next 4-wide stop
+        let res = get_chunks_of_tabs(4, "aa\tbb");
+
+        assert_eq!(res, vec![(2, 3, 2)]);
+    }
+
+    #[test]
+    fn test_resets_per_line() {
+        // column tracking restarts on each line, so both tabs expand the same way
+        let res = get_chunks_of_tabs(4, "aa\tbb\naa\tbb");
+
+        assert_eq!(res, vec![(2, 3, 2), (8, 9, 2)]);
+    }
+
+    #[test]
+    fn test_custom_width() {
+        let res = get_chunks_of_tabs(2, "a\t\taa");
+
+        assert_eq!(res, vec![(1, 3, 3)]);
+    }
+
+    #[test]
+    fn test_multibyte_before_tab() {
+        // "é" is 2 bytes but a single column, so the tab's byte offset must account for that
+        let res = get_chunks_of_tabs(4, "é\taa");
+
+        assert_eq!(res, vec![(2, 3, 3)]);
+    }
 }
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+/// Clippy configuration, parsed from a `clippy.toml` file found in the package's directory (or
+/// one of its parents), and used to tune the behaviour of configurable lints.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields, default)]
+pub struct Conf {
+    /// The tab width, in columns, used when expanding a tab inside a doc comment to the next
+    /// tab stop (see `TABS_IN_DOC_COMMENTS`).
+    pub doc_tab_width: u32,
+}
+
+impl Default for Conf {
+    fn default() -> Self {
+        Self { doc_tab_width: 4 }
+    }
+}
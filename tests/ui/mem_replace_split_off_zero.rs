@@ -0,0 +1,15 @@
+// run-rustfix
+
+#![warn(clippy::mem_replace_split_off_zero)]
+
+fn main() {
+    let mut vec = vec![1, 2, 3];
+    let _taken = vec.split_off(0);
+
+    let mut s = String::from("foo");
+    let _taken = s.split_off(0);
+
+    // Should not lint, not draining from the start.
+    let mut vec2 = vec![1, 2, 3];
+    let _taken2 = vec2.split_off(1);
+}
@@ -0,0 +1,13 @@
+// run-rustfix
+
+#![warn(clippy::option_map_unwrap_or_else)]
+
+fn main() {
+    let x = Some(10);
+    // Should lint.
+    let _ = x.map(|a| a + 1).unwrap_or_else(|| 0);
+
+    // Should not lint, `y` is used in both closures.
+    let y = 5;
+    let _ = x.map(|a| a + y).unwrap_or_else(|| y);
+}
@@ -0,0 +1,20 @@
+// run-rustfix
+
+#![warn(clippy::strlen_on_c_strings)]
+
+use std::ffi::{CStr, CString};
+
+fn main() {
+    let cstr = CString::new("foo").unwrap();
+    let cstr = cstr.as_c_str();
+
+    let _ = unsafe { libc::strlen(cstr.as_ptr()) };
+
+    let owned = CString::new("foo").unwrap();
+    let _ = unsafe { libc::strlen(owned.as_ptr()) };
+
+    // Should not lint, argument doesn't come from a `CStr`/`CString`.
+    let _ = unsafe { libc::strlen(std::ptr::null()) };
+}
+
+fn _unused(_s: &CStr) {}